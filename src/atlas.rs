@@ -0,0 +1,212 @@
+use crate::error::GasError;
+use glow::HasContext;
+use std::sync::Arc;
+
+/// Fixed atlas dimensions (width == height).
+const ATLAS_SIZE: u32 = 1024;
+
+/// A horizontal strip of the atlas holding images of similar height.
+struct Shelf {
+    y: u32,
+    height: u32,
+    x_cursor: u32,
+}
+
+/// UV sub-rectangle of a packed image within an `Atlas`.
+#[derive(Clone, Copy, Debug)]
+pub struct AtlasRect {
+    pub u0: f32,
+    pub v0: f32,
+    pub u1: f32,
+    pub v1: f32,
+}
+
+/// Shelf-packing bookkeeping for `Atlas`, kept free of any GL dependency so
+/// placement decisions are unit-testable without a real `glow::Context`.
+///
+/// Finds the first shelf tall enough with enough horizontal room; if none
+/// fits, opens a new shelf at the current bottom.
+struct ShelfPacker {
+    size: u32,
+    shelves: Vec<Shelf>,
+    bottom_y: u32,
+}
+
+impl ShelfPacker {
+    fn new(size: u32) -> Self {
+        Self {
+            size,
+            shelves: Vec::new(),
+            bottom_y: 0,
+        }
+    }
+
+    /// Reserves room for a `(width, height)` image and returns its `(x, y)`
+    /// position in the atlas. Returns `None` if it doesn't fit anywhere.
+    fn place(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        let shelf_index = self
+            .shelves
+            .iter()
+            .position(|shelf| shelf.height >= height && self.size - shelf.x_cursor >= width);
+
+        if let Some(index) = shelf_index {
+            let shelf = &mut self.shelves[index];
+            let x = shelf.x_cursor;
+            shelf.x_cursor += width;
+            Some((x, shelf.y))
+        } else {
+            if width > self.size || self.bottom_y + height > self.size {
+                return None; // Doesn't fit, even on a fresh shelf.
+            }
+
+            let y = self.bottom_y;
+            self.shelves.push(Shelf {
+                y,
+                height,
+                x_cursor: width,
+            });
+            self.bottom_y += height;
+            Some((0, y))
+        }
+    }
+}
+
+/// **Packs many small images into one large GL texture**
+///
+/// Uses a shelf packer: images are placed left-to-right along a shelf, and
+/// a new shelf is opened below the previous one when nothing existing fits.
+/// This keeps draws to a single texture bind instead of one per sprite.
+pub struct Atlas {
+    gl: Arc<glow::Context>,
+    texture: glow::Texture,
+    packer: ShelfPacker,
+}
+
+impl Atlas {
+    pub fn new(gl: Arc<glow::Context>) -> Result<Self, GasError> {
+        Self::with_size(gl, ATLAS_SIZE)
+    }
+
+    pub fn with_size(gl: Arc<glow::Context>, size: u32) -> Result<Self, GasError> {
+        let texture = unsafe {
+            let texture = gl.create_texture().map_err(|e| {
+                GasError::GlObjectCreation(format!("Failed to create atlas texture: {e}"))
+            })?;
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+
+            // Allocate storage up front; sub-regions are filled in by `insert`.
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::RGBA as i32,
+                size as i32,
+                size as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(None),
+            );
+
+            texture
+        };
+
+        Ok(Self {
+            gl,
+            texture,
+            packer: ShelfPacker::new(size),
+        })
+    }
+
+    pub fn texture(&self) -> glow::Texture {
+        self.texture
+    }
+
+    /// **Packs a `(width, height)` RGBA image into the atlas**
+    ///
+    /// Delegates placement to `ShelfPacker::place`; returns `None` if the
+    /// image doesn't fit in the remaining atlas space.
+    pub fn insert(&mut self, width: u32, height: u32, data: &[u8]) -> Option<AtlasRect> {
+        let (x, y) = self.packer.place(width, height)?;
+
+        unsafe {
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+            self.gl.tex_sub_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                x as i32,
+                y as i32,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(Some(data)),
+            );
+        }
+
+        let size = self.packer.size as f32;
+        Some(AtlasRect {
+            u0: x as f32 / size,
+            v0: y as f32 / size,
+            u1: (x + width) as f32 / size,
+            v1: (y + height) as f32 / size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place_packs_images_left_to_right_on_one_shelf() {
+        let mut packer = ShelfPacker::new(64);
+
+        assert_eq!(packer.place(10, 10), Some((0, 0)));
+        assert_eq!(packer.place(20, 8), Some((10, 0)));
+        assert_eq!(packer.place(5, 10), Some((30, 0)));
+    }
+
+    #[test]
+    fn place_opens_a_new_shelf_when_height_does_not_fit() {
+        let mut packer = ShelfPacker::new(64);
+
+        assert_eq!(packer.place(10, 10), Some((0, 0)));
+        // Taller than the existing shelf, so it can't reuse it.
+        assert_eq!(packer.place(10, 20), Some((0, 10)));
+        // Still fits on the first (shorter) shelf, to the right of the first image.
+        assert_eq!(packer.place(5, 5), Some((10, 0)));
+    }
+
+    #[test]
+    fn place_returns_none_when_nothing_fits() {
+        let mut packer = ShelfPacker::new(16);
+
+        assert_eq!(packer.place(20, 4), None); // Wider than the whole atlas.
+        assert_eq!(packer.place(4, 20), None); // Taller than the whole atlas.
+
+        // Fill the atlas height exactly, then anything else overflows it.
+        assert_eq!(packer.place(16, 16), Some((0, 0)));
+        assert_eq!(packer.place(1, 1), None);
+    }
+}
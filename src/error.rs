@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Errors surfaced by the `gasrs` rendering pipeline.
+///
+/// Lets embedders recover (fall back, show an error UI) instead of the
+/// whole app/WASM module aborting on a panic.
+#[derive(Debug)]
+pub enum GasError {
+    /// Failed to create or acquire a GL/WebGL context, window, or surface.
+    ContextCreation(String),
+    /// A network/fetch request for a texture failed.
+    Network(String),
+    /// Image decoding failed (native `image` crate or browser decode).
+    ImageDecode(String),
+    /// Failed to create a GL object (texture, buffer, VAO, shader, program).
+    GlObjectCreation(String),
+    /// Shader compilation or program linking failed; carries the GL info log.
+    ShaderCompile(String),
+}
+
+impl fmt::Display for GasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GasError::ContextCreation(msg) => write!(f, "failed to create GL context: {msg}"),
+            GasError::Network(msg) => write!(f, "network error: {msg}"),
+            GasError::ImageDecode(msg) => write!(f, "image decode error: {msg}"),
+            GasError::GlObjectCreation(msg) => write!(f, "failed to create GL object: {msg}"),
+            GasError::ShaderCompile(msg) => write!(f, "shader compile/link error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GasError {}
@@ -0,0 +1,17 @@
+/// Cross-platform input events dispatched to the handler registered via
+/// `View::set_input_handler`.
+///
+/// Native translates winit's `WindowEvent` variants into these; wasm
+/// translates `keydown`/`keyup`/`pointermove`/`pointerdown` canvas events.
+/// Keycodes are the platform's own key code (winit `KeyCode` discriminant on
+/// native, `KeyboardEvent.keyCode` on web) rather than a normalized layout, so
+/// they're comparable across events but not guaranteed identical across
+/// platforms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputEvent {
+    KeyPress(u32),
+    KeyRelease(u32),
+    MouseMove { x: f32, y: f32 },
+    MouseButton { button: u8, pressed: bool },
+    Resize { width: u32, height: u32 },
+}
@@ -1,4 +1,9 @@
+mod atlas;
+mod error;
+mod input;
 mod view;
+use error::GasError;
+use input::InputEvent;
 use view::View;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -18,7 +23,7 @@ mod web {
     pub use wasm_bindgen::closure::Closure;
     pub use wasm_bindgen::JsCast;
     pub use wasm_bindgen_futures::spawn_local;
-    pub use web_sys::window; // ✅ Import JsCast
+    pub use web_sys::{console, window}; // ✅ Import console + window
 }
 
 // ✅ Import conditionally based on platform
@@ -27,12 +32,33 @@ use native::*;
 #[cfg(target_arch = "wasm32")]
 use web::*;
 
-/// **Common async setup function (Runs in both Native & WebAssembly)**
-async fn setup(view: &mut View) {
+/// Demo texture loaded by `setup` and drawn every frame by the render loops below.
+const CREDIT_CARD_URL: &str = "https://m.media-amazon.com/images/G/01/credit/CBCC/acq-marketing/maple/Q123-1103_US_CBCC_ACQ_Maple_Thumbnail_126x80._CB613265021_.png";
+/// Pixel size baked into the source filename (`..._126x80_...`).
+const CREDIT_CARD_SIZE: (f32, f32) = (126.0, 80.0);
+
+/// **Common async setup function (Native)**
+#[cfg(not(target_arch = "wasm32"))]
+async fn setup(view: &mut View) -> Result<(), GasError> {
     // Load a texture asynchronously
-    let credit_card = "https://m.media-amazon.com/images/G/01/credit/CBCC/acq-marketing/maple/Q123-1103_US_CBCC_ACQ_Maple_Thumbnail_126x80._CB613265021_.png";
-    view.load_texture(credit_card).await;
-    view.bind_texture(credit_card);
+    view.load_texture(CREDIT_CARD_URL, true).await?;
+    view.bind_texture(CREDIT_CARD_URL);
+    Ok(())
+}
+
+/// **Common async setup function (WebAssembly)**
+///
+/// Takes the `Rc<RefCell<View>>` itself and only borrows it for the
+/// synchronous upload step. Holding a `borrow_mut()` across the network
+/// fetch would panic any input or context-loss listener that borrows
+/// `view` while setup is still awaiting its response.
+#[cfg(target_arch = "wasm32")]
+async fn setup(view: Rc<RefCell<View>>) -> Result<(), GasError> {
+    let fetched = View::fetch_texture(CREDIT_CARD_URL, true).await?;
+    let mut view_mut = view.borrow_mut();
+    view_mut.store_fetched_texture(CREDIT_CARD_URL, true, fetched)?;
+    view_mut.bind_texture(CREDIT_CARD_URL);
+    Ok(())
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -51,6 +77,20 @@ impl<'a> MyApp<'a> {
     }
 }
 
+/// Maps winit's `MouseButton` onto the small numeric id `InputEvent::MouseButton` carries.
+#[cfg(not(target_arch = "wasm32"))]
+fn mouse_button_code(button: winit::event::MouseButton) -> u8 {
+    use winit::event::MouseButton;
+    match button {
+        MouseButton::Left => 0,
+        MouseButton::Right => 1,
+        MouseButton::Middle => 2,
+        MouseButton::Back => 3,
+        MouseButton::Forward => 4,
+        MouseButton::Other(code) => code as u8,
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 impl<'a> ApplicationHandler for MyApp<'a> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
@@ -63,15 +103,53 @@ impl<'a> ApplicationHandler for MyApp<'a> {
         _window_id: winit::window::WindowId,
         event: winit::event::WindowEvent,
     ) {
-        if event == winit::event::WindowEvent::CloseRequested {
-            println!("Closing window...");
-            event_loop.exit();
+        use winit::event::WindowEvent;
+
+        match event {
+            WindowEvent::CloseRequested => {
+                println!("Closing window...");
+                event_loop.exit();
+            }
+            WindowEvent::Resized(size) => {
+                self.view.resize(size.width, size.height);
+                self.view.dispatch_input(InputEvent::Resize {
+                    width: size.width,
+                    height: size.height,
+                });
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                self.view.dispatch_input(InputEvent::MouseMove {
+                    x: position.x as f32,
+                    y: position.y as f32,
+                });
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                self.view.dispatch_input(InputEvent::MouseButton {
+                    button: mouse_button_code(button),
+                    pressed: state == winit::event::ElementState::Pressed,
+                });
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                let keycode = match event.physical_key {
+                    winit::keyboard::PhysicalKey::Code(code) => code as u32,
+                    winit::keyboard::PhysicalKey::Unidentified(_) => return,
+                };
+                self.view.dispatch_input(match event.state {
+                    winit::event::ElementState::Pressed => InputEvent::KeyPress(keycode),
+                    winit::event::ElementState::Released => InputEvent::KeyRelease(keycode),
+                });
+            }
+            _ => {}
         }
     }
 
     fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
         let elapsed_time: f64 = self.start_time.elapsed().as_millis() as f64; // ✅ Convert to ms
         self.view.render_frame(elapsed_time);
+        let (w, h) = CREDIT_CARD_SIZE;
+        self.view
+            .draw_texture(CREDIT_CARD_URL, 20.0, 20.0, w, h)
+            .expect("Failed to draw credit card sprite");
         self.view
             .surface
             .swap_buffers(&self.view.gl_context)
@@ -84,8 +162,9 @@ impl<'a> ApplicationHandler for MyApp<'a> {
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::main]
 async fn main() {
-    let (mut view, event_loop) = View::new(800, 600);
-    setup(&mut view).await;
+    let (mut view, event_loop) = View::new(800, 600).expect("Failed to create view");
+    setup(&mut view).await.expect("Failed to set up view");
+    view.set_input_handler(|event| println!("🎮 Input event: {event:?}"));
     let mut my_app = MyApp::new(&mut view);
     event_loop.run_app(&mut my_app).unwrap();
 }
@@ -97,8 +176,11 @@ pub fn main() {
     console_error_panic_hook::set_once();
 
     spawn_local(async move {
-        let view = Rc::new(RefCell::new(View::new(800, 600))); // ✅ Use `Rc<RefCell<View>>`
-        setup(&mut view.borrow_mut()).await; // ✅ Now mutable borrow works!
+        let view = View::new(800, 600).expect("Failed to create view"); // ✅ Returns `Rc<RefCell<View>>` so context-loss recovery can rebuild it
+        setup(view.clone()).await.expect("Failed to set up view");
+        view.borrow_mut().set_input_handler(|event| {
+            console::log_1(&format!("🎮 Input event: {event:?}").into())
+        });
 
         let performance = window()
             .unwrap()
@@ -115,7 +197,13 @@ pub fn main() {
 
             move || {
                 let now = performance.now();
-                view.borrow().render_frame(now); // ✅ Call render function
+                let view_ref = view.borrow();
+                view_ref.render_frame(now); // ✅ Call render function
+                let (w, h) = CREDIT_CARD_SIZE;
+                view_ref
+                    .draw_texture(CREDIT_CARD_URL, 20.0, 20.0, w, h)
+                    .expect("Failed to draw credit card sprite");
+                drop(view_ref);
 
                 if let Some(callback) = closure_clone.borrow().as_ref() {
                     request_animation_frame(callback);
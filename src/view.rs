@@ -1,3 +1,6 @@
+use crate::atlas::{Atlas, AtlasRect};
+use crate::error::GasError;
+use crate::input::InputEvent;
 use glow::HasContext;
 use image::DynamicImage;
 use std::collections::HashMap;
@@ -16,7 +19,7 @@ mod native {
             PossiblyCurrentContext, Version,
         },
         display::{GetGlDisplay, GlDisplay},
-        surface::{SurfaceAttributesBuilder, WindowSurface},
+        surface::{GlSurface, SurfaceAttributesBuilder, WindowSurface},
     };
     pub use glutin_winit::DisplayBuilder;
 
@@ -28,9 +31,15 @@ mod native {
 // 🌍 **Web (WASM) Imports**
 #[cfg(target_arch = "wasm32")]
 mod web {
+    pub use std::cell::{Cell, RefCell};
+    pub use std::rc::Rc;
+    pub use wasm_bindgen::closure::Closure;
     pub use wasm_bindgen::JsCast;
-    pub use wasm_bindgen_futures::JsFuture;
-    pub use web_sys::{console, window, HtmlCanvasElement, Response, WebGl2RenderingContext};
+    pub use wasm_bindgen_futures::{spawn_local, JsFuture};
+    pub use web_sys::{
+        console, window, Event, HtmlCanvasElement, HtmlImageElement, KeyboardEvent,
+        OffscreenCanvas, PointerEvent, Response, WebGl2RenderingContext,
+    };
 }
 
 // ✅ Import conditionally based on platform
@@ -39,27 +48,143 @@ use native::*;
 #[cfg(target_arch = "wasm32")]
 use web::*;
 
+// 🎨 **Shader sources for the sprite pipeline**
+//
+// The `#version` header differs between desktop GL 3.3 core and WebGL2
+// (GLSL ES 3.00), so it's kept separate from the shader body.
+#[cfg(not(target_arch = "wasm32"))]
+const VERTEX_HEADER: &str = "#version 330 core\n";
+#[cfg(target_arch = "wasm32")]
+const VERTEX_HEADER: &str = "#version 300 es\n";
+
+#[cfg(not(target_arch = "wasm32"))]
+const FRAGMENT_HEADER: &str = "#version 330 core\n";
+#[cfg(target_arch = "wasm32")]
+const FRAGMENT_HEADER: &str = "#version 300 es\nprecision mediump float;\n";
+
+const VERTEX_SHADER_SRC: &str = r#"
+layout(location = 0) in vec2 a_pos;
+layout(location = 1) in vec2 a_uv;
+
+uniform mat4 u_mvp;
+uniform vec2 u_uv_offset;
+uniform vec2 u_uv_scale;
+
+out vec2 v_uv;
+
+void main() {
+    v_uv = a_uv * u_uv_scale + u_uv_offset;
+    gl_Position = u_mvp * vec4(a_pos, 0.0, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER_SRC: &str = r#"
+in vec2 v_uv;
+out vec4 frag_color;
+
+uniform sampler2D u_texture;
+
+void main() {
+    frag_color = texture(u_texture, v_uv);
+}
+"#;
+
+/// A loaded texture, either its own GL texture or a sub-rect of the shared atlas.
+#[derive(Clone, Copy)]
+pub enum TextureHandle {
+    Standalone(glow::Texture),
+    Atlas(AtlasRect),
+}
+
+/// Decoded texture data awaiting GPU upload, returned by `View::fetch_texture`.
+///
+/// Kept separate from `View` so the network fetch can run without holding a
+/// `Rc<RefCell<View>>` borrow; `View::store_fetched_texture` consumes it.
+#[cfg(target_arch = "wasm32")]
+pub enum FetchedTexture {
+    Pixels {
+        width: u32,
+        height: u32,
+        data: Vec<u8>,
+    },
+    Image(HtmlImageElement),
+}
+
+/// **Decodes an image via the browser instead of the `image` crate**
+///
+/// Creates an `HtmlImageElement`, points it at `url`, and awaits its
+/// `load`/`error` event through a `js_sys::Promise` driven from a
+/// one-shot `Closure` pair, so the browser does the PNG/JPEG decode.
+#[cfg(target_arch = "wasm32")]
+async fn load_html_image(url: &str) -> Result<HtmlImageElement, GasError> {
+    let image = HtmlImageElement::new()
+        .map_err(|_| GasError::ImageDecode("Failed to create HtmlImageElement".into()))?;
+    let image_for_listeners = image.clone();
+
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let onload = Closure::once_into_js(move || {
+            let _ = resolve.call0(&wasm_bindgen::JsValue::NULL);
+        });
+        let onerror = Closure::once_into_js(move || {
+            let _ = reject.call0(&wasm_bindgen::JsValue::NULL);
+        });
+        image_for_listeners.set_onload(Some(onload.unchecked_ref()));
+        image_for_listeners.set_onerror(Some(onerror.unchecked_ref()));
+    });
+
+    image.set_cross_origin(Some("anonymous"));
+    image.set_src(url);
+
+    JsFuture::from(promise)
+        .await
+        .map_err(|_| GasError::ImageDecode(format!("Failed to load image: {url}")))?;
+
+    Ok(image)
+}
+
 pub struct View {
     gl: Arc<glow::Context>,
-    textures: HashMap<String, glow::Texture>,
+    textures: HashMap<String, TextureHandle>,
+    atlas: Atlas,
     width: u32,
     height: u32,
 
+    /// Sprite shader program, compiled once per GL context.
+    program: glow::Program,
+    /// Vertex array describing the unit quad used by `draw_texture`.
+    vao: glow::VertexArray,
+    /// Backing buffer for `vao` (position + UV, interleaved).
+    vbo: glow::Buffer,
+
+    /// User callback registered via `set_input_handler`, invoked by `dispatch_input`.
+    input_handler: Option<Box<dyn FnMut(InputEvent)>>,
+
     #[cfg(not(target_arch = "wasm32"))]
     pub window: Arc<Window>,
     #[cfg(not(target_arch = "wasm32"))]
     pub gl_context: glutin::context::PossiblyCurrentContext,
     #[cfg(not(target_arch = "wasm32"))]
     pub surface: glutin::surface::Surface<glutin::surface::WindowSurface>,
+
+    /// Raw context, kept alongside `gl` so we can reach `web_sys` texture
+    /// upload overloads (e.g. `HtmlImageElement` sources) glow doesn't expose.
+    #[cfg(target_arch = "wasm32")]
+    webgl: WebGl2RenderingContext,
+
+    /// Set by the `webglcontextlost` listener; `render_frame` becomes a
+    /// no-op while this is true, until `webglcontextrestored` fires.
+    #[cfg(target_arch = "wasm32")]
+    context_lost: Cell<bool>,
 }
 
 impl View {
     /// **Creates a new View and initializes OpenGL/WebGL**
     #[cfg(not(target_arch = "wasm32"))]
-    pub fn new(width: u32, height: u32) -> (Self, EventLoop<()>) {
+    pub fn new(width: u32, height: u32) -> Result<(Self, EventLoop<()>), GasError> {
         use winit::raw_window_handle::HasRawWindowHandle;
 
-        let event_loop = EventLoop::new().expect("Failed to create EventLoop");
+        let event_loop = EventLoop::new()
+            .map_err(|e| GasError::ContextCreation(format!("Failed to create EventLoop: {e}")))?;
 
         let window_attributes = WindowAttributes::default()
             .with_title("Rust OpenGL Window")
@@ -71,38 +196,48 @@ impl View {
             .build(&event_loop, ConfigTemplateBuilder::new(), |mut configs| {
                 configs.next().unwrap()
             })
-            .unwrap();
+            .map_err(|e| GasError::ContextCreation(format!("Failed to build display: {e}")))?;
 
-        let window = Arc::new(window.unwrap()); // Unwrap because `Some` window exists
+        let window = Arc::new(
+            window.ok_or_else(|| GasError::ContextCreation("No window was created".into()))?,
+        );
 
         // ✅ Define OpenGL context attributes
         let raw_context = ContextAttributesBuilder::new()
             .with_profile(GlProfile::Core)
             .with_context_api(ContextApi::OpenGl(Some(Version::new(3, 3)))) // OpenGL 3.3 Core Profile
-            .build(Some(window.raw_window_handle().unwrap()));
+            .build(Some(window.raw_window_handle().map_err(|e| {
+                GasError::ContextCreation(format!("Failed to get raw window handle: {e}"))
+            })?));
 
         // ✅ Create OpenGL context
         let not_current_gl_context = unsafe {
             gl_config
                 .display()
                 .create_context(&gl_config, &raw_context)
-                .unwrap()
+                .map_err(|e| GasError::ContextCreation(format!("Failed to create context: {e}")))?
         };
 
         // ✅ Create a surface
         let surface_attributes = SurfaceAttributesBuilder::<WindowSurface>::new().build(
-            window.raw_window_handle().unwrap(),
-            NonZeroU32::new(width).unwrap(),
-            NonZeroU32::new(height).unwrap(),
+            window.raw_window_handle().map_err(|e| {
+                GasError::ContextCreation(format!("Failed to get raw window handle: {e}"))
+            })?,
+            NonZeroU32::new(width)
+                .ok_or_else(|| GasError::ContextCreation("Width must be non-zero".into()))?,
+            NonZeroU32::new(height)
+                .ok_or_else(|| GasError::ContextCreation("Height must be non-zero".into()))?,
         );
         let surface = unsafe {
             gl_config
                 .display()
                 .create_window_surface(&gl_config, &surface_attributes)
-                .unwrap()
+                .map_err(|e| GasError::ContextCreation(format!("Failed to create surface: {e}")))?
         };
 
-        let gl_context = not_current_gl_context.make_current(&surface).unwrap();
+        let gl_context = not_current_gl_context.make_current(&surface).map_err(|e| {
+            GasError::ContextCreation(format!("Failed to make context current: {e}"))
+        })?;
 
         // ✅ Load OpenGL function pointers
         let gl = Arc::new(unsafe {
@@ -116,85 +251,341 @@ impl View {
             gl.viewport(0, 0, width as i32, height as i32); // ✅ Ensure viewport matches window
         }
 
-        (
+        let program = Self::create_shader_program(&gl)?;
+        let (vao, vbo) = Self::create_quad(&gl)?;
+        let atlas = Atlas::new(gl.clone())?;
+
+        Ok((
             Self {
                 gl,
                 gl_context,
                 height,
                 surface,
                 textures: HashMap::new(),
+                atlas,
                 width,
                 window,
+                program,
+                vao,
+                vbo,
+                input_handler: None,
             },
             event_loop,
-        )
+        ))
     }
 
+    /// Returns a `Rc<RefCell<View>>` (rather than a bare `View`) because the
+    /// `webglcontextrestored` listener set up below needs shared, mutable
+    /// access to rebuild the GL context from outside the constructor.
     #[cfg(target_arch = "wasm32")]
-    pub fn new(width: u32, height: u32) -> Self {
+    pub fn new(width: u32, height: u32) -> Result<Rc<RefCell<Self>>, GasError> {
         console::log_1(&"✅ View::new() - Starting initialization".into());
 
-        let window = match window() {
-            Some(win) => {
-                console::log_1(&"✅ window() returned successfully".into());
-                win
-            }
-            None => {
-                console::log_1(
-                    &"❌ ERROR: `window()` returned None. Are you running in a browser?".into(),
-                );
-                panic!("❌ ERROR: `window()` returned None. Are you running in a browser?");
-            }
-        };
+        let window = window().ok_or_else(|| {
+            console::log_1(
+                &"❌ ERROR: `window()` returned None. Are you running in a browser?".into(),
+            );
+            GasError::ContextCreation("`window()` returned None".into())
+        })?;
+        console::log_1(&"✅ window() returned successfully".into());
 
-        let document = match window.document() {
-            Some(doc) => {
-                console::log_1(&"✅ document() returned successfully".into());
-                doc
-            }
-            None => {
-                console::log_1(&"❌ ERROR: `document()` returned None. Is JavaScript blocking access to the DOM?".into());
-                panic!("❌ ERROR: `document()` returned None. Is JavaScript blocking access to the DOM?");
-            }
-        };
+        let document = window.document().ok_or_else(|| {
+            console::log_1(
+                &"❌ ERROR: `document()` returned None. Is JavaScript blocking access to the DOM?"
+                    .into(),
+            );
+            GasError::ContextCreation("`document()` returned None".into())
+        })?;
+        console::log_1(&"✅ document() returned successfully".into());
 
-        let canvas = match document.get_element_by_id("canvas") {
-            Some(c) => {
-                console::log_1(&"✅ Canvas element found in the DOM".into());
-                c.dyn_into::<HtmlCanvasElement>().unwrap()
-            }
-            None => {
+        let canvas = document
+            .get_element_by_id("canvas")
+            .ok_or_else(|| {
                 console::log_1(
                     &"❌ ERROR: Canvas element with id 'canvas' not found in the DOM".into(),
                 );
-                panic!("❌ ERROR: Canvas element with id 'canvas' not found in the DOM");
-            }
-        };
+                GasError::ContextCreation("Canvas element with id 'canvas' not found".into())
+            })?
+            .dyn_into::<HtmlCanvasElement>()
+            .map_err(|_| GasError::ContextCreation("Element 'canvas' is not a canvas".into()))?;
+        console::log_1(&"✅ Canvas element found in the DOM".into());
 
         canvas.set_width(width);
         canvas.set_height(height);
+        canvas.set_tab_index(0); // Canvas must be focusable to receive key events.
 
         let webgl_context = match canvas.get_context("webgl2") {
-            Ok(Some(ctx)) => {
-                console::log_1(&"✅ WebGL2 context created successfully".into());
-                ctx.dyn_into::<WebGl2RenderingContext>().unwrap()
-            }
+            Ok(Some(ctx)) => ctx.dyn_into::<WebGl2RenderingContext>().map_err(|_| {
+                GasError::ContextCreation(
+                    "'webgl2' context was not a WebGl2RenderingContext".into(),
+                )
+            })?,
             _ => {
                 console::log_1(&"❌ ERROR: WebGL2 context could not be created. Your browser may not support WebGL2.".into());
-                panic!("❌ ERROR: WebGL2 context could not be created. Your browser may not support WebGL2.");
+                return Err(GasError::ContextCreation(
+                    "WebGL2 context could not be created".into(),
+                ));
             }
         };
-
+        console::log_1(&"✅ WebGL2 context created successfully".into());
         console::log_1(&"✅ Glow WebGL context initialization successful".into());
 
-        let gl = Arc::new(glow::Context::from_webgl2_context(webgl_context));
+        let view = Rc::new(RefCell::new(Self::from_webgl2(
+            webgl_context,
+            width,
+            height,
+        )?));
+        Self::watch_context_loss(&view, &canvas)?;
+        Self::watch_input_events(&view, &canvas)?;
+        Ok(view)
+    }
+
+    /// Wires `webglcontextlost`/`webglcontextrestored` listeners onto `canvas`.
+    ///
+    /// On loss, `event.preventDefault()` tells the browser we intend to
+    /// restore, and `render_frame` is made a no-op via `context_lost`. On
+    /// restore, the GL context, shader program, atlas and VAO/VBO are
+    /// rebuilt from scratch and every cached texture is reloaded by its key
+    /// (the key is the original load URL/path).
+    #[cfg(target_arch = "wasm32")]
+    fn watch_context_loss(
+        view: &Rc<RefCell<Self>>,
+        canvas: &HtmlCanvasElement,
+    ) -> Result<(), GasError> {
+        let lost_view = view.clone();
+        let on_lost = Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+            event.prevent_default();
+            lost_view.borrow().context_lost.set(true);
+        });
+        canvas
+            .add_event_listener_with_callback("webglcontextlost", on_lost.as_ref().unchecked_ref())
+            .map_err(|_| {
+                GasError::ContextCreation("Failed to register webglcontextlost listener".into())
+            })?;
+        on_lost.forget();
+
+        let restored_view = view.clone();
+        let restored_canvas = canvas.clone();
+        let on_restored = Closure::<dyn FnMut(Event)>::new(move |_event: Event| {
+            Self::restore_gl_context(restored_view.clone(), restored_canvas.clone());
+        });
+        canvas
+            .add_event_listener_with_callback(
+                "webglcontextrestored",
+                on_restored.as_ref().unchecked_ref(),
+            )
+            .map_err(|_| {
+                GasError::ContextCreation("Failed to register webglcontextrestored listener".into())
+            })?;
+        on_restored.forget();
+
+        Ok(())
+    }
+
+    /// Wires `keydown`/`keyup`/`pointermove`/`pointerdown` listeners onto
+    /// `canvas`, translating each into an `InputEvent` and dispatching it to
+    /// the user's handler.
+    #[cfg(target_arch = "wasm32")]
+    fn watch_input_events(
+        view: &Rc<RefCell<Self>>,
+        canvas: &HtmlCanvasElement,
+    ) -> Result<(), GasError> {
+        let keydown_view = view.clone();
+        let on_keydown = Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+            keydown_view
+                .borrow_mut()
+                .dispatch_input(InputEvent::KeyPress(event.key_code()));
+        });
+        canvas
+            .add_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref())
+            .map_err(|_| GasError::ContextCreation("Failed to register keydown listener".into()))?;
+        on_keydown.forget();
+
+        let keyup_view = view.clone();
+        let on_keyup = Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+            keyup_view
+                .borrow_mut()
+                .dispatch_input(InputEvent::KeyRelease(event.key_code()));
+        });
+        canvas
+            .add_event_listener_with_callback("keyup", on_keyup.as_ref().unchecked_ref())
+            .map_err(|_| GasError::ContextCreation("Failed to register keyup listener".into()))?;
+        on_keyup.forget();
+
+        let move_view = view.clone();
+        let on_pointermove = Closure::<dyn FnMut(PointerEvent)>::new(move |event: PointerEvent| {
+            move_view
+                .borrow_mut()
+                .dispatch_input(InputEvent::MouseMove {
+                    x: event.offset_x() as f32,
+                    y: event.offset_y() as f32,
+                });
+        });
+        canvas
+            .add_event_listener_with_callback(
+                "pointermove",
+                on_pointermove.as_ref().unchecked_ref(),
+            )
+            .map_err(|_| {
+                GasError::ContextCreation("Failed to register pointermove listener".into())
+            })?;
+        on_pointermove.forget();
+
+        let down_view = view.clone();
+        let on_pointerdown = Closure::<dyn FnMut(PointerEvent)>::new(move |event: PointerEvent| {
+            down_view
+                .borrow_mut()
+                .dispatch_input(InputEvent::MouseButton {
+                    button: event.button() as u8,
+                    pressed: true,
+                });
+        });
+        canvas
+            .add_event_listener_with_callback(
+                "pointerdown",
+                on_pointerdown.as_ref().unchecked_ref(),
+            )
+            .map_err(|_| {
+                GasError::ContextCreation("Failed to register pointerdown listener".into())
+            })?;
+        on_pointerdown.forget();
+
+        Ok(())
+    }
+
+    /// Rebuilds GL resources after `webglcontextrestored` and reloads every
+    /// previously cached texture (atlas membership is preserved per key).
+    ///
+    /// Runs from an event listener with no caller to hand a `Result` back
+    /// to, so failures are logged to the console rather than propagated.
+    #[cfg(target_arch = "wasm32")]
+    fn restore_gl_context(view: Rc<RefCell<Self>>, canvas: HtmlCanvasElement) {
+        let result = (|| -> Result<Vec<(String, bool)>, GasError> {
+            let mut view_mut = view.borrow_mut();
+
+            let webgl_context = canvas
+                .get_context("webgl2")
+                .map_err(|_| GasError::ContextCreation("Failed to get WebGL2 context".into()))?
+                .ok_or_else(|| {
+                    GasError::ContextCreation("Canvas did not return a WebGL2 context".into())
+                })?
+                .dyn_into::<WebGl2RenderingContext>()
+                .map_err(|_| {
+                    GasError::ContextCreation("Context was not a WebGl2RenderingContext".into())
+                })?;
+
+            let gl = Arc::new(glow::Context::from_webgl2_context(webgl_context.clone()));
+            view_mut.program = Self::create_shader_program(&gl)?;
+            let (vao, vbo) = Self::create_quad(&gl)?;
+            view_mut.vao = vao;
+            view_mut.vbo = vbo;
+            view_mut.atlas = Atlas::new(gl.clone())?;
+            view_mut.gl = gl;
+            view_mut.webgl = webgl_context;
+
+            let to_reload: Vec<(String, bool)> = view_mut
+                .textures
+                .drain()
+                .map(|(key, handle)| (key, matches!(handle, TextureHandle::Atlas(_))))
+                .collect();
+
+            view_mut.context_lost.set(false);
+            Ok(to_reload)
+        })();
+
+        let to_reload = match result {
+            Ok(to_reload) => to_reload,
+            Err(e) => {
+                console::log_1(&format!("❌ ERROR: Failed to restore WebGL context: {e}").into());
+                return;
+            }
+        };
+
+        spawn_local(async move {
+            for (key, use_atlas) in to_reload {
+                // Fetch without holding the cell borrowed, then reborrow just
+                // for the synchronous upload — an input or context-loss
+                // listener firing mid-fetch would otherwise hit a panicking
+                // double `borrow_mut()`.
+                let result = Self::fetch_texture(&key, use_atlas)
+                    .await
+                    .and_then(|fetched| {
+                        view.borrow_mut()
+                            .store_fetched_texture(&key, use_atlas, fetched)
+                    });
+                if let Err(e) = result {
+                    console::log_1(
+                        &format!("❌ ERROR: Failed to reload texture '{key}': {e}").into(),
+                    );
+                }
+            }
+        });
+    }
+
+    /// **Creates a View from an `OffscreenCanvas`, for rendering in a Web Worker**
+    ///
+    /// Lets the render loop run off the main thread: the caller transfers an
+    /// `OffscreenCanvas` from the DOM canvas into a worker and builds the
+    /// `View` there, avoiding main-thread jank.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_offscreen_canvas(
+        canvas: OffscreenCanvas,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, GasError> {
+        canvas.set_width(width);
+        canvas.set_height(height);
+
+        let context_options = js_sys::Object::new();
+        js_sys::Reflect::set(&context_options, &"antialias".into(), &false.into())
+            .map_err(|_| GasError::ContextCreation("Failed to set WebGL2 context option".into()))?;
+
+        let webgl_context = canvas
+            .get_context_with_context_options("webgl2", &context_options)
+            .map_err(|_| {
+                GasError::ContextCreation(
+                    "Failed to get WebGL2 context from OffscreenCanvas".into(),
+                )
+            })?
+            .ok_or_else(|| {
+                GasError::ContextCreation("OffscreenCanvas did not return a WebGL2 context".into())
+            })?
+            .dyn_into::<WebGl2RenderingContext>()
+            .map_err(|_| {
+                GasError::ContextCreation("Context was not a WebGl2RenderingContext".into())
+            })?;
+
+        Self::from_webgl2(webgl_context, width, height)
+    }
+
+    /// Shared setup for any source of a `WebGl2RenderingContext` (DOM canvas
+    /// or `OffscreenCanvas`): wraps it in `glow`, compiles the sprite
+    /// pipeline, and builds the texture atlas.
+    #[cfg(target_arch = "wasm32")]
+    fn from_webgl2(
+        webgl_context: WebGl2RenderingContext,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, GasError> {
+        let gl = Arc::new(glow::Context::from_webgl2_context(webgl_context.clone()));
+
+        let program = Self::create_shader_program(&gl)?;
+        let (vao, vbo) = Self::create_quad(&gl)?;
+        let atlas = Atlas::new(gl.clone())?;
 
-        Self {
+        Ok(Self {
             gl,
             textures: HashMap::new(),
+            atlas,
             width,
             height,
-        }
+            program,
+            vao,
+            vbo,
+            input_handler: None,
+            webgl: webgl_context,
+            context_lost: Cell::new(false),
+        })
     }
 
     /// **Handles window resizing**
@@ -206,6 +597,12 @@ impl View {
         {
             self.window
                 .request_inner_size(winit::dpi::PhysicalSize::new(new_width, new_height));
+
+            if let (Some(width), Some(height)) =
+                (NonZeroU32::new(new_width), NonZeroU32::new(new_height))
+            {
+                self.surface.resize(&self.gl_context, width, height);
+            }
         }
 
         #[cfg(target_arch = "wasm32")]
@@ -222,49 +619,231 @@ impl View {
             canvas.set_width(new_width);
             canvas.set_height(new_height);
         }
+
+        // The surface/canvas backing store just changed size; without this the
+        // viewport stays pinned to whatever `View::new` set it to and the
+        // render no longer fills the window.
+        unsafe {
+            self.gl
+                .viewport(0, 0, new_width as i32, new_height as i32);
+        }
+    }
+
+    /// **Registers the callback invoked by `dispatch_input` for every input event**
+    pub fn set_input_handler<F: FnMut(InputEvent) + 'static>(&mut self, handler: F) {
+        self.input_handler = Some(Box::new(handler));
+    }
+
+    /// Forwards `event` to the handler registered via `set_input_handler`, if any.
+    pub fn dispatch_input(&mut self, event: InputEvent) {
+        if let Some(handler) = &mut self.input_handler {
+            handler(event);
+        }
     }
 
     /// **Loads a texture asynchronously and caches it by path (Native)**
+    ///
+    /// When `use_atlas` is set, the image is packed into the shared `Atlas`
+    /// instead of getting its own GL texture, so many sprites can share one
+    /// bind. Falls back to a standalone texture if the atlas is full.
     #[cfg(not(target_arch = "wasm32"))]
-    pub async fn load_texture(&mut self, path: &str) {
-        if self.textures.contains_key(path) {
-            return; // Texture is already loaded
+    pub async fn load_texture(
+        &mut self,
+        path: &str,
+        use_atlas: bool,
+    ) -> Result<TextureHandle, GasError> {
+        if let Some(handle) = self.textures.get(path) {
+            return Ok(*handle);
         }
 
-        let response = reqwest::get(path).await.expect("Failed to fetch image");
-        let bytes = response.bytes().await.expect("Failed to read image bytes");
+        let response = reqwest::get(path)
+            .await
+            .map_err(|e| GasError::Network(format!("Failed to fetch '{path}': {e}")))?;
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| GasError::Network(format!("Failed to read image bytes: {e}")))?;
 
-        let img = image::load_from_memory(&bytes).expect("Failed to decode image");
+        let img = image::load_from_memory(&bytes)
+            .map_err(|e| GasError::ImageDecode(format!("Failed to decode '{path}': {e}")))?;
         let (width, height, data) = Self::decode_image(img);
 
-        let texture = self.upload_texture(width, height, &data);
-        self.textures.insert(path.to_string(), texture);
+        let handle = self.store_texture(use_atlas, width, height, &data)?;
+        self.textures.insert(path.to_string(), handle);
+        Ok(handle)
     }
 
     /// **Loads a texture asynchronously and caches it by URL (WebAssembly)**
+    ///
+    /// When `use_atlas` is false, decoding is handed off to the browser via
+    /// an `HtmlImageElement` instead of running the `image` crate's decoder,
+    /// which saves bundle size and is faster for PNG/JPEG. The atlas packer
+    /// needs raw pixels to write into its sub-rect, so atlas uploads still
+    /// go through the byte-decode path below.
+    ///
+    /// Callers juggling a `Rc<RefCell<View>>` (the common wasm case) should
+    /// prefer `fetch_texture` + `store_fetched_texture` instead of this
+    /// method: this one takes `&mut self` for its whole body, so awaiting it
+    /// through a `borrow_mut()` holds that borrow across the network fetch.
     #[cfg(target_arch = "wasm32")]
-    pub async fn load_texture(&mut self, url: &str) {
-        if self.textures.contains_key(url) {
-            return; // Texture is already loaded
+    pub async fn load_texture(
+        &mut self,
+        url: &str,
+        use_atlas: bool,
+    ) -> Result<TextureHandle, GasError> {
+        if let Some(handle) = self.textures.get(url) {
+            return Ok(*handle);
         }
+        let fetched = Self::fetch_texture(url, use_atlas).await?;
+        self.store_fetched_texture(url, use_atlas, fetched)
+    }
 
-        let response: Response = JsFuture::from(window().unwrap().fetch_with_str(url))
-            .await
-            .expect("Failed to fetch image")
-            .dyn_into()
-            .unwrap();
+    /// Fetches and decodes a texture without touching `View`.
+    ///
+    /// Split out of `load_texture` so a caller holding a `Rc<RefCell<View>>`
+    /// can `.await` the network fetch without keeping the cell borrowed —
+    /// pair with `store_fetched_texture`, acquiring `borrow_mut()` only for
+    /// that second, synchronous call.
+    #[cfg(target_arch = "wasm32")]
+    pub async fn fetch_texture(url: &str, use_atlas: bool) -> Result<FetchedTexture, GasError> {
+        if use_atlas {
+            let bytes = Self::fetch_bytes(url).await?;
+            let img = image::load_from_memory(&bytes)
+                .map_err(|e| GasError::ImageDecode(format!("Failed to decode '{url}': {e}")))?;
+            let (width, height, data) = Self::decode_image(img);
+            Ok(FetchedTexture::Pixels {
+                width,
+                height,
+                data,
+            })
+        } else {
+            Ok(FetchedTexture::Image(load_html_image(url).await?))
+        }
+    }
 
-        let buffer = JsFuture::from(response.array_buffer().unwrap())
-            .await
-            .expect("Failed to get array buffer");
+    /// Uploads a texture already fetched by `fetch_texture` and caches it by
+    /// `url`. Synchronous, so it's safe to call right after a `borrow_mut()`.
+    #[cfg(target_arch = "wasm32")]
+    pub fn store_fetched_texture(
+        &mut self,
+        url: &str,
+        use_atlas: bool,
+        fetched: FetchedTexture,
+    ) -> Result<TextureHandle, GasError> {
+        if let Some(handle) = self.textures.get(url) {
+            return Ok(*handle);
+        }
+
+        let handle = match fetched {
+            FetchedTexture::Pixels {
+                width,
+                height,
+                data,
+            } => self.store_texture(use_atlas, width, height, &data)?,
+            FetchedTexture::Image(image) => {
+                TextureHandle::Standalone(self.upload_texture_from_image(&image)?)
+            }
+        };
 
-        let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
-        let img = image::load_from_memory(&bytes).expect("Failed to decode image");
+        self.textures.insert(url.to_string(), handle);
+        Ok(handle)
+    }
 
-        let (width, height, data) = Self::decode_image(img);
+    /// Fetches `url` and returns its raw bytes, for the decode-ourselves path.
+    #[cfg(target_arch = "wasm32")]
+    async fn fetch_bytes(url: &str) -> Result<Vec<u8>, GasError> {
+        let response: Response = JsFuture::from(
+            window()
+                .ok_or_else(|| GasError::ContextCreation("`window()` returned None".into()))?
+                .fetch_with_str(url),
+        )
+        .await
+        .map_err(|_| GasError::Network(format!("Failed to fetch '{url}'")))?
+        .dyn_into()
+        .map_err(|_| GasError::Network("Fetch did not return a Response".into()))?;
+
+        let buffer = JsFuture::from(
+            response
+                .array_buffer()
+                .map_err(|_| GasError::Network("Response has no array_buffer".into()))?,
+        )
+        .await
+        .map_err(|_| GasError::Network("Failed to get array buffer".into()))?;
+
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
+    /// Uploads an already-decoded `HtmlImageElement` straight to a GL texture,
+    /// letting `WebGl2RenderingContext` read pixels from the image itself.
+    #[cfg(target_arch = "wasm32")]
+    fn upload_texture_from_image(
+        &self,
+        image: &HtmlImageElement,
+    ) -> Result<glow::Texture, GasError> {
+        unsafe {
+            let texture = self.gl.create_texture().map_err(|e| {
+                GasError::GlObjectCreation(format!("Failed to create texture: {e}"))
+            })?;
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+            self.gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::LINEAR as i32,
+            );
+            self.gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::LINEAR as i32,
+            );
+            self.gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_S,
+                glow::CLAMP_TO_EDGE as i32,
+            );
+            self.gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_WRAP_T,
+                glow::CLAMP_TO_EDGE as i32,
+            );
 
-        let texture = self.upload_texture(width, height, &data);
-        self.textures.insert(url.to_string(), texture);
+            self.webgl
+                .tex_image_2d_with_u32_and_u32_and_html_image_element(
+                    glow::TEXTURE_2D,
+                    0,
+                    glow::RGBA as i32,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    image,
+                )
+                .map_err(|_| {
+                    GasError::GlObjectCreation(
+                        "Failed to upload HtmlImageElement to texture".into(),
+                    )
+                })?;
+
+            Ok(texture)
+        }
+    }
+
+    /// Uploads decoded RGBA pixels, routing into the atlas when requested and possible.
+    fn store_texture(
+        &mut self,
+        use_atlas: bool,
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) -> Result<TextureHandle, GasError> {
+        if use_atlas {
+            if let Some(rect) = self.atlas.insert(width, height, data) {
+                return Ok(TextureHandle::Atlas(rect));
+            }
+            // Atlas is full; fall back to a standalone texture below.
+        }
+
+        Ok(TextureHandle::Standalone(
+            self.upload_texture(width, height, data)?,
+        ))
     }
 
     fn decode_image(img: DynamicImage) -> (u32, u32, Vec<u8>) {
@@ -273,9 +852,16 @@ impl View {
         (width, height, img.into_raw())
     }
 
-    fn upload_texture(&self, width: u32, height: u32, data: &[u8]) -> glow::Texture {
+    fn upload_texture(
+        &self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) -> Result<glow::Texture, GasError> {
         unsafe {
-            let texture = self.gl.create_texture().expect("Failed to create texture");
+            let texture = self.gl.create_texture().map_err(|e| {
+                GasError::GlObjectCreation(format!("Failed to create texture: {e}"))
+            })?;
             self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
 
             self.gl.tex_parameter_i32(
@@ -311,19 +897,233 @@ impl View {
                 glow::PixelUnpackData::Slice(Some(data)),
             );
 
-            texture
+            Ok(texture)
         }
     }
 
     pub fn bind_texture(&self, path: &str) {
+        let Some(handle) = self.textures.get(path) else {
+            return;
+        };
+
+        let texture = match *handle {
+            TextureHandle::Standalone(texture) => texture,
+            TextureHandle::Atlas(_) => self.atlas.texture(),
+        };
+
+        unsafe {
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        }
+    }
+
+    /// **Compiles and links the sprite shader program**
+    fn create_shader_program(gl: &glow::Context) -> Result<glow::Program, GasError> {
+        unsafe {
+            let program = gl.create_program().map_err(|e| {
+                GasError::GlObjectCreation(format!("Failed to create shader program: {e}"))
+            })?;
+
+            let vertex_src = format!("{VERTEX_HEADER}{VERTEX_SHADER_SRC}");
+            let fragment_src = format!("{FRAGMENT_HEADER}{FRAGMENT_SHADER_SRC}");
+
+            let vertex_shader = Self::compile_shader(gl, glow::VERTEX_SHADER, &vertex_src)?;
+            let fragment_shader = Self::compile_shader(gl, glow::FRAGMENT_SHADER, &fragment_src)?;
+
+            gl.attach_shader(program, vertex_shader);
+            gl.attach_shader(program, fragment_shader);
+            gl.link_program(program);
+
+            if !gl.get_program_link_status(program) {
+                return Err(GasError::ShaderCompile(format!(
+                    "Failed to link shader program: {}",
+                    gl.get_program_info_log(program)
+                )));
+            }
+
+            gl.detach_shader(program, vertex_shader);
+            gl.detach_shader(program, fragment_shader);
+            gl.delete_shader(vertex_shader);
+            gl.delete_shader(fragment_shader);
+
+            Ok(program)
+        }
+    }
+
+    fn compile_shader(
+        gl: &glow::Context,
+        shader_type: u32,
+        source: &str,
+    ) -> Result<glow::Shader, GasError> {
+        unsafe {
+            let shader = gl
+                .create_shader(shader_type)
+                .map_err(|e| GasError::GlObjectCreation(format!("Failed to create shader: {e}")))?;
+            gl.shader_source(shader, source);
+            gl.compile_shader(shader);
+
+            if !gl.get_shader_compile_status(shader) {
+                return Err(GasError::ShaderCompile(format!(
+                    "Failed to compile shader: {}",
+                    gl.get_shader_info_log(shader)
+                )));
+            }
+
+            Ok(shader)
+        }
+    }
+
+    /// **Builds the unit quad (position + UV) used by `draw_texture`**
+    fn create_quad(gl: &glow::Context) -> Result<(glow::VertexArray, glow::Buffer), GasError> {
         unsafe {
-            if let Some(texture) = self.textures.get(path) {
-                self.gl.bind_texture(glow::TEXTURE_2D, Some(*texture));
+            let vao = gl
+                .create_vertex_array()
+                .map_err(|e| GasError::GlObjectCreation(format!("Failed to create VAO: {e}")))?;
+            let vbo = gl
+                .create_buffer()
+                .map_err(|e| GasError::GlObjectCreation(format!("Failed to create VBO: {e}")))?;
+
+            gl.bind_vertex_array(Some(vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
+
+            #[rustfmt::skip]
+            let vertices: [f32; 24] = [
+                // pos        // uv
+                0.0, 1.0,     0.0, 1.0,
+                1.0, 0.0,     1.0, 0.0,
+                0.0, 0.0,     0.0, 0.0,
+
+                0.0, 1.0,     0.0, 1.0,
+                1.0, 1.0,     1.0, 1.0,
+                1.0, 0.0,     1.0, 0.0,
+            ];
+
+            let bytes = std::slice::from_raw_parts(
+                vertices.as_ptr() as *const u8,
+                std::mem::size_of_val(&vertices),
+            );
+            gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, bytes, glow::STATIC_DRAW);
+
+            let stride = 4 * std::mem::size_of::<f32>() as i32;
+            gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
+            gl.enable_vertex_attrib_array(0);
+            gl.vertex_attrib_pointer_f32(
+                1,
+                2,
+                glow::FLOAT,
+                false,
+                stride,
+                2 * std::mem::size_of::<f32>() as i32,
+            );
+            gl.enable_vertex_attrib_array(1);
+
+            gl.bind_buffer(glow::ARRAY_BUFFER, None);
+            gl.bind_vertex_array(None);
+
+            Ok((vao, vbo))
+        }
+    }
+
+    /// **Draws the cached texture at `path` as a screen-space quad**
+    ///
+    /// `x`, `y`, `w`, `h` are in pixels, with the origin at the top-left
+    /// of the view (matching `self.width`/`self.height`).
+    pub fn draw_texture(&self, path: &str, x: f32, y: f32, w: f32, h: f32) -> Result<(), GasError> {
+        let Some(handle) = self.textures.get(path) else {
+            return Ok(());
+        };
+
+        let (texture, uv_offset, uv_scale) = match *handle {
+            TextureHandle::Standalone(texture) => (texture, [0.0, 0.0], [1.0, 1.0]),
+            TextureHandle::Atlas(rect) => (
+                self.atlas.texture(),
+                [rect.u0, rect.v0],
+                [rect.u1 - rect.u0, rect.v1 - rect.v0],
+            ),
+        };
+
+        unsafe {
+            self.gl.use_program(Some(self.program));
+
+            let projection = Self::orthographic_projection(self.width as f32, self.height as f32);
+            let model = Self::model_matrix(x, y, w, h);
+            let mvp = Self::multiply_matrices(&projection, &model);
+
+            let mvp_location = self.gl.get_uniform_location(self.program, "u_mvp");
+            self.gl
+                .uniform_matrix_4_f32_slice(mvp_location.as_ref(), false, &mvp);
+
+            let uv_offset_location = self.gl.get_uniform_location(self.program, "u_uv_offset");
+            self.gl
+                .uniform_2_f32_slice(uv_offset_location.as_ref(), &uv_offset);
+            let uv_scale_location = self.gl.get_uniform_location(self.program, "u_uv_scale");
+            self.gl
+                .uniform_2_f32_slice(uv_scale_location.as_ref(), &uv_scale);
+
+            self.gl.active_texture(glow::TEXTURE0);
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            let texture_location = self.gl.get_uniform_location(self.program, "u_texture");
+            self.gl.uniform_1_i32(texture_location.as_ref(), 0);
+
+            self.gl.bind_vertex_array(Some(self.vao));
+            self.gl.draw_arrays(glow::TRIANGLES, 0, 6);
+            self.gl.bind_vertex_array(None);
+        }
+
+        Ok(())
+    }
+
+    /// Orthographic projection mapping pixel space (origin top-left) to clip space.
+    fn orthographic_projection(width: f32, height: f32) -> [f32; 16] {
+        let (l, r, b, t, n, f) = (0.0, width, height, 0.0, -1.0, 1.0);
+
+        [
+            2.0 / (r - l),
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            2.0 / (t - b),
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            -2.0 / (f - n),
+            0.0,
+            -(r + l) / (r - l),
+            -(t + b) / (t - b),
+            -(f + n) / (f - n),
+            1.0,
+        ]
+    }
+
+    /// Scale+translate matrix placing the unit quad at `(x, y)` sized `(w, h)`.
+    fn model_matrix(x: f32, y: f32, w: f32, h: f32) -> [f32; 16] {
+        [
+            w, 0.0, 0.0, 0.0, 0.0, h, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, x, y, 0.0, 1.0,
+        ]
+    }
+
+    /// Column-major 4x4 matrix multiplication: `a * b`.
+    fn multiply_matrices(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+        let mut result = [0.0f32; 16];
+        for row in 0..4 {
+            for col in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += a[k * 4 + row] * b[col * 4 + k];
+                }
+                result[col * 4 + row] = sum;
             }
         }
+        result
     }
 
     pub fn render_frame(&self, time_ms: f64) {
+        #[cfg(target_arch = "wasm32")]
+        if self.context_lost.get() {
+            return; // Wait for `webglcontextrestored` before drawing again.
+        }
+
         let time_sec = time_ms / 1000.0; // ✅ Convert milliseconds to seconds
 
         let r = (time_sec.sin() * 0.5 + 0.5) as f32; // ✅ Cycles every 2π seconds
@@ -336,3 +1136,57 @@ impl View {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies a column-major 4x4 matrix to a homogeneous point.
+    fn transform(m: &[f32; 16], p: [f32; 4]) -> [f32; 4] {
+        let mut out = [0.0f32; 4];
+        for row in 0..4 {
+            out[row] = (0..4).map(|col| m[col * 4 + row] * p[col]).sum();
+        }
+        out
+    }
+
+    #[test]
+    fn orthographic_projection_maps_pixel_corners_to_clip_space() {
+        let proj = View::orthographic_projection(800.0, 600.0);
+
+        // Top-left pixel -> top-left of clip space (-1, 1).
+        let top_left = transform(&proj, [0.0, 0.0, 0.0, 1.0]);
+        assert!((top_left[0] - -1.0).abs() < 1e-6);
+        assert!((top_left[1] - 1.0).abs() < 1e-6);
+
+        // Bottom-right pixel -> bottom-right of clip space (1, -1).
+        let bottom_right = transform(&proj, [800.0, 600.0, 0.0, 1.0]);
+        assert!((bottom_right[0] - 1.0).abs() < 1e-6);
+        assert!((bottom_right[1] - -1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn model_matrix_scales_and_translates_the_unit_quad() {
+        let model = View::model_matrix(100.0, 50.0, 200.0, 100.0);
+
+        // The quad's (1, 1) corner lands at (x + w, y + h).
+        let corner = transform(&model, [1.0, 1.0, 0.0, 1.0]);
+        assert_eq!(corner, [300.0, 150.0, 0.0, 1.0]);
+
+        // The quad's origin lands at (x, y).
+        let origin = transform(&model, [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(origin, [100.0, 50.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn mvp_places_a_sprite_vertex_at_its_expected_clip_coordinate() {
+        let proj = View::orthographic_projection(800.0, 600.0);
+        let model = View::model_matrix(100.0, 50.0, 200.0, 100.0);
+        let mvp = View::multiply_matrices(&proj, &model);
+
+        // Sprite's bottom-right corner: pixel (300, 150) in an 800x600 view.
+        let clip = transform(&mvp, [1.0, 1.0, 0.0, 1.0]);
+        assert!((clip[0] - -0.25).abs() < 1e-6);
+        assert!((clip[1] - 0.5).abs() < 1e-6);
+    }
+}